@@ -3,6 +3,7 @@ use url::Url;
 use constants::*;
 use hyper::Request;
 use std::num::Wrapping;
+use std::time::Duration;
 use http::{Uri, request::Builder, header::CONTENT_TYPE};
 
 /// Main struct from which API requests are built
@@ -10,8 +11,11 @@ use http::{Uri, request::Builder, header::CONTENT_TYPE};
 /// * factomd/walletd/debug hold the request builders to which a json body 
 /// is added
 /// * uri is the current uri locations
-/// * id is the json-rpc id field as a wrapped usize, 
+/// * id is the json-rpc id field as a wrapped usize,
 /// it can be incremented without risking overflow
+/// * factomd_uris is the ordered list of factomd endpoints tried in turn on
+/// failure; factomd_uri is always the head of this list
+/// * retry holds the backoff policy applied to each endpoint before rotating
 #[derive(Debug)]
 pub struct Factom{
   pub client: HttpsClient,
@@ -19,11 +23,38 @@ pub struct Factom{
   pub walletd: Builder,
   pub debug: Builder,
   pub factomd_uri: Uri,
+  pub factomd_uris: Vec<Uri>,
   pub walletd_uri: Uri,
   pub debug_uri: Uri,
+  pub retry: RetryPolicy,
   pub id: Wrapping<usize>
 }
 
+/// Controls how a factomd call retries a single endpoint before rotating to the
+/// next one. The delay grows geometrically from `base_delay` by `multiplier`
+/// each attempt, with up to `jitter` (as a fraction of the delay) added to
+/// spread out retries against a busy node.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+  pub max_attempts: u32,
+  pub base_delay: Duration,
+  pub multiplier: f64,
+  pub jitter: f64,
+}
+
+impl Default for RetryPolicy {
+  /// Three attempts per endpoint starting at 500ms, doubling each time, with
+  /// 10% jitter.
+  fn default() -> Self {
+    RetryPolicy {
+      max_attempts: 3,
+      base_delay: Duration::from_millis(500),
+      multiplier: 2.0,
+      jitter: 0.1,
+    }
+  }
+}
+
 impl Factom {
   /// Creates a factom struct with the default host locations, equivalent to
   /// Factom::local_node()
@@ -47,9 +78,11 @@ impl Factom {
       factomd: request_builder(factomd_uri.clone()),
       walletd: request_builder(walletd_uri.clone()),
       debug: request_builder(debug_uri.clone()),
+      factomd_uris: vec![factomd_uri.clone()],
       factomd_uri,
       walletd_uri,
       debug_uri,
+      retry: RetryPolicy::default(),
       id: Wrapping(ID)
     }
   }
@@ -68,9 +101,11 @@ impl Factom {
       factomd: request_builder(factomd_uri.clone()),
       walletd: request_builder(walletd_uri.clone()),
       debug: request_builder(debug_uri.clone()),
+      factomd_uris: vec![factomd_uri.clone()],
       factomd_uri,
       walletd_uri,
       debug_uri,
+      retry: RetryPolicy::default(),
       id: Wrapping(ID)
     }
   }
@@ -89,9 +124,11 @@ impl Factom {
       factomd: request_builder(factomd_uri.clone()),
       walletd: request_builder(walletd_uri.clone()),
       debug: request_builder(debug_uri.clone()),
+      factomd_uris: vec![factomd_uri.clone()],
       factomd_uri,
       walletd_uri,
       debug_uri,
+      retry: RetryPolicy::default(),
       id: Wrapping(ID)
     }
   }
@@ -113,14 +150,54 @@ impl Factom {
       factomd: request_builder(factomd_uri.clone()),
       walletd: request_builder(walletd_uri.clone()),
       debug: request_builder(debug_uri.clone()),
+      factomd_uris: vec![factomd_uri.clone()],
+      factomd_uri,
+      walletd_uri,
+      debug_uri,
+      retry: RetryPolicy::default(),
+      id: Wrapping(ID)
+    }
+  }
+
+  /// Creates a factom struct backed by an ordered list of factomd endpoints.
+  /// A factomd call tries the first endpoint, retrying it with exponential
+  /// backoff per the default [`RetryPolicy`], and rotates to the next endpoint
+  /// once a node is exhausted. The wallet host uses the default local
+  /// location and the debug host is derived from the first factomd endpoint.
+  /// An empty list falls back to the default local factomd host.
+  ///
+  /// # Example
+  /// ```
+  /// use factom::*;
+  /// let api = Factom::with_endpoints(
+  ///   vec!["https://api.factomd.net", "http://localhost:8088"]
+  /// );
+  /// ```
+  pub fn with_endpoints(factomd: Vec<&str>) -> Factom {
+    let mut factomd_uris: Vec<Uri> = factomd.iter().map(|host| parse_uri(host)).collect();
+    if factomd_uris.is_empty() {
+      factomd_uris.push(parse_uri(FACTOMD_DEFAULT));
+    }
+    let factomd_uri = factomd_uris[0].clone();
+    let walletd_uri = parse_uri(WALLETD_DEFAULT);
+    let debug_uri = parse_debug_uri(
+      factomd.first().copied().unwrap_or(FACTOMD_DEFAULT),
+    );
+    Factom{
+      client: new_client(),
+      factomd: request_builder(factomd_uri.clone()),
+      walletd: request_builder(walletd_uri.clone()),
+      debug: request_builder(debug_uri.clone()),
+      factomd_uris,
       factomd_uri,
       walletd_uri,
       debug_uri,
+      retry: RetryPolicy::default(),
       id: Wrapping(ID)
     }
   }
 
-  /// Increments the json-rpc id by one. Will wrap around to zero if it goes 
+  /// Increments the json-rpc id by one. Will wrap around to zero if it goes
   /// over [std::usize::MAX](https://doc.rust-lang.org/std/usize/constant.MAX.html)
   pub fn increment_id(mut self) {
     self.id += Wrapping(1);
@@ -161,8 +238,10 @@ impl Clone for Factom {
       walletd,
       debug,
       factomd_uri: self.factomd_uri.clone(),
+      factomd_uris: self.factomd_uris.clone(),
       walletd_uri: self.walletd_uri.clone(),
       debug_uri: self.debug_uri.clone(),
+      retry: self.retry.clone(),
       id: self.id
     }
   }
@@ -1,4 +1,7 @@
 use super::*;
+use std::fmt;
+use std::str::FromStr;
+use futures::future::join_all;
 
 impl Factom{
   /**
@@ -72,10 +75,16 @@ balances for a list of entry credit addresses.
 square bracket, it will return: `{“jsonrpc”:“2.0”,“id”:null,“error”:
 {“code”:-32600,“message”:“Invalid Request”}}`
 
-* If the parameters are labeled incorrectly the call will return: 
-`{“code”:-32602,“message”:“Invalid params”,“data”:“ERROR! Invalid params passed 
+* If the parameters are labeled incorrectly the call will return:
+`{“code”:-32602,“message”:“Invalid params”,“data”:“ERROR! Invalid params passed
 in, expected addresses”}`
 
+* Note: the address list is chunked and the chunks are fetched concurrently,
+then merged. This method now resolves to `ApiResponse<MultipleBalances>` (the
+true shape of the JSON response, carrying `currentheight`/`lastsavedheight`
+alongside the per-address balances); this is a breaking change from the
+previous `ApiResponse<Balances>` return type.
+
 * If factomd is not loaded up all the way to the last saved block it will 
 return: `{“currentheight”:0,“lastsavedheight”:0,“balances”:[{“ack”:0,“saved”:0,
 “err”:“Not fully booted”}]}`
@@ -102,14 +111,11 @@ assert!(response.success());
 ```
 */
   pub async fn multiple_ec_balances(
-    self, 
+    self,
     addresses: Vec<&str>
-  )-> Result<ApiResponse<Balances>>
+  )-> Result<ApiResponse<MultipleBalances>>
   {
-    let mut req =  ApiRequest::new("multiple-ec-balances");
-    req.params.insert("addresses".to_string(), json!(addresses));
-    let response = self.factomd_call(req).await;
-    parse(response).await
+    self.chunked_balances("multiple-ec-balances", addresses, BALANCE_CHUNK).await
   }
 
 /**
@@ -142,9 +148,15 @@ return: `{“currentheight”:0,“lastsavedheight”:0,“balances”:
 will return: `{“currentheight”:0,“lastsavedheight”:0,
 “balances”:[{“ack”:0,“saved”:0,“err”:“Error decoding address”}]}`
 
-* If an address in the list is valid but has never been part of a transaction 
-it will return: `“balances”:[{“ack”:0,“saved”:0,“err”:“Address has not had a 
+* If an address in the list is valid but has never been part of a transaction
+it will return: `“balances”:[{“ack”:0,“saved”:0,“err”:“Address has not had a
 transaction”}]`
+
+* Note: the address list is chunked and the chunks are fetched concurrently,
+then merged. This method now resolves to `ApiResponse<MultipleBalances>` (the
+true shape of the JSON response, carrying `currentheight`/`lastsavedheight`
+alongside the per-address balances); this is a breaking change from the
+previous `ApiResponse<Balances>` return type.
 # Example
 ```
 use factom::*;
@@ -159,23 +171,182 @@ assert!(response.success());
 ```
 */
   pub async fn multiple_fct_balances(
-    self, 
+    self,
     addresses: Vec<&str>
-    )-> Result<ApiResponse<Balances>>
+    )-> Result<ApiResponse<MultipleBalances>>
     {
-    let mut req =  ApiRequest::new("multiple-fct-balances");
-    req.params.insert("addresses".to_string(), json!(addresses));
-    let response = self.factomd_call(req).await;
-    parse(response).await
+    self.chunked_balances("multiple-fct-balances", addresses, BALANCE_CHUNK).await
+  }
+
+  /// Splits `addresses` into `chunk_size`-sized batches, dispatches one
+  /// `method` request per batch concurrently and merges the results back into a
+  /// single [`MultipleBalances`]: the heights become the max seen across
+  /// chunks and the per-address balances are concatenated in the original input
+  /// order. Lists at or below `chunk_size` take a single round trip.
+  async fn chunked_balances(
+    self,
+    method: &str,
+    addresses: Vec<&str>,
+    chunk_size: usize,
+  )-> Result<ApiResponse<MultipleBalances>>
+  {
+    // An empty list is a valid call: preserve the original single-request
+    // behavior rather than dispatching zero chunks and inventing an error.
+    let empty: [&str; 0] = [];
+    let batches: Vec<&[&str]> = if addresses.is_empty() {
+      vec![&empty[..]]
+    } else {
+      addresses.chunks(chunk_size).collect()
+    };
+
+    let calls = batches.into_iter().map(|chunk| {
+      let mut req = ApiRequest::new(method);
+      req.params.insert("addresses".to_string(), json!(chunk));
+      async {
+        let response = self.factomd_call(req).await;
+        parse::<MultipleBalances>(response).await
+      }
+    });
+    // join_all preserves input order, so the concatenated balances line up with
+    // the caller's original address list.
+    let results = join_all(calls).await;
+
+    let mut merged: Option<ApiResponse<MultipleBalances>> = None;
+    for result in results {
+      let response = result?;
+      match merged.as_mut() {
+        None => merged = Some(response),
+        Some(acc) => acc.result.merge(response.result),
+      }
+    }
+    // At least one batch (possibly the empty one above) is always dispatched.
+    Ok(merged.expect("at least one batch is always dispatched"))
   }
 }
 
+/// Default number of addresses packed into a single `multiple-*-balances`
+/// request before the list is split across concurrent calls.
+const BALANCE_CHUNK: usize = 100;
+
 /// entry-credit-balance and factoid-balance functions
 #[derive(Default, Debug, Clone, PartialEq, serde_derive::Serialize, serde_derive::Deserialize)]
 pub struct Balance {
     balance: i64,
 }
 
+impl Balance {
+  /// The balance as a typed [`Factoshi`] amount.
+  pub fn balance(&self) -> Factoshi {
+    Factoshi(self.balance)
+  }
+
+  /// Builds a `Balance` from a raw factoshi count. Used internally by the
+  /// polling watch streams, which synthesise a `Balance` from an observed
+  /// value.
+  pub(crate) fn from_factoshis(balance: i64) -> Self {
+    Balance { balance }
+  }
+}
+
+/// Number of factoshis in a whole factoid.
+pub const FACTOSHIS_PER_FACTOID: i64 = 100_000_000;
+
+/// An amount denominated in factoshis, the base unit of the Factoid currency
+/// (1 FCT = 10^8 factoshis). Wrapping the raw `i64` keeps factoshi amounts from
+/// being silently mixed with whole-factoid display values, a recurring source
+/// of off-by-10^8 bugs.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Factoshi(i64);
+
+impl Factoshi {
+  /// Wraps a raw factoshi count.
+  pub fn new(factoshis: i64) -> Self {
+    Factoshi(factoshis)
+  }
+
+  /// The raw factoshi count.
+  pub fn factoshis(self) -> i64 {
+    self.0
+  }
+
+  /// Builds an amount from a whole number of factoids, returning `None` if the
+  /// conversion would overflow an `i64`.
+  pub fn from_factoids(factoids: i64) -> Option<Self> {
+    factoids.checked_mul(FACTOSHIS_PER_FACTOID).map(Factoshi)
+  }
+
+  /// The whole-factoid part of the amount, truncating any fractional
+  /// factoshis towards zero.
+  pub fn as_factoids(self) -> i64 {
+    self.0 / FACTOSHIS_PER_FACTOID
+  }
+}
+
+impl fmt::Display for Factoshi {
+  /// Formats the amount as factoids with the full eight decimal places, e.g.
+  /// `12345678` factoshis renders as `0.12345678`.
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    let sign = if self.0 < 0 { "-" } else { "" };
+    // unsigned_abs avoids the overflow that `i64::abs` hits on `i64::MIN`.
+    let magnitude = self.0.unsigned_abs();
+    let per = FACTOSHIS_PER_FACTOID as u64;
+    write!(f, "{}{}.{:08}", sign, magnitude / per, magnitude % per)
+  }
+}
+
+impl FromStr for Factoshi {
+  type Err = ParseFactoshiError;
+
+  /// Parses a decimal factoid string (e.g. `"1.5"` or `"0.12345678"`) into
+  /// factoshis. Up to eight decimal places are accepted.
+  fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+    let (sign, digits) = match s.strip_prefix('-') {
+      Some(rest) => (-1, rest),
+      None => (1, s),
+    };
+    let mut parts = digits.splitn(2, '.');
+    let whole = parts.next().unwrap_or("");
+    let frac = parts.next().unwrap_or("");
+    if frac.len() > 8 {
+      return Err(ParseFactoshiError(format!("too many decimal places: {}", s)));
+    }
+    // Reject input that carries no digits at all, e.g. "", "." or "-".
+    if whole.is_empty() && frac.is_empty() {
+      return Err(ParseFactoshiError(format!("no digits in amount: {:?}", s)));
+    }
+    let parse = |field: &str| -> std::result::Result<i64, ParseFactoshiError> {
+      if field.is_empty() {
+        Ok(0)
+      } else {
+        field.parse::<i64>().map_err(|_| ParseFactoshiError(format!("invalid amount: {}", s)))
+      }
+    };
+    let whole = parse(whole)?;
+    // Pad the fractional part out to the full eight factoshi digits.
+    let frac = parse(&format!("{:0<8}", frac))?;
+    // Checked throughout, matching `from_factoids`, so large inputs error
+    // rather than wrapping silently.
+    let factoshis = whole
+      .checked_mul(FACTOSHIS_PER_FACTOID)
+      .and_then(|w| w.checked_add(frac))
+      .and_then(|v| v.checked_mul(sign))
+      .ok_or_else(|| ParseFactoshiError(format!("amount out of range: {}", s)))?;
+    Ok(Factoshi(factoshis))
+  }
+}
+
+/// Error returned when a string cannot be parsed into a [`Factoshi`] amount.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseFactoshiError(String);
+
+impl fmt::Display for ParseFactoshiError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{}", self.0)
+  }
+}
+
+impl std::error::Error for ParseFactoshiError {}
+
 
 /// Struct for deserialising multiple-fct-balances and multiple-ec-balances
 #[derive(Default, Debug, Clone, PartialEq, serde_derive::Serialize, serde_derive::Deserialize)]
@@ -185,9 +356,132 @@ pub struct MultipleBalances {
     balances: Vec<Balances>,
 }
 
+impl MultipleBalances {
+  /// The current height factomd was loading when it answered.
+  pub fn currentheight(&self) -> i64 {
+    self.currentheight
+  }
+
+  /// The height last saved to the database.
+  pub fn lastsavedheight(&self) -> i64 {
+    self.lastsavedheight
+  }
+
+  /// The per-address balances, in the original request order.
+  pub fn balances(&self) -> &[Balances] {
+    &self.balances
+  }
+
+  /// Folds another chunk's result into this one, taking the greater of each
+  /// height and appending its balances so order across chunks is preserved.
+  fn merge(&mut self, other: MultipleBalances) {
+    self.currentheight = self.currentheight.max(other.currentheight);
+    self.lastsavedheight = self.lastsavedheight.max(other.lastsavedheight);
+    self.balances.extend(other.balances);
+  }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, serde_derive::Serialize, serde_derive::Deserialize)]
 pub struct Balances {
     ack: i64,
     saved: i64,
     err: String,
+}
+
+impl Balances {
+  /// Inspects the per-address `err` field and returns the `(ack, saved)` pair
+  /// on success, or the matching [`FactomError`] node-state condition so
+  /// callers can branch on "not booted yet" versus "never transacted" rather
+  /// than string-matching the `err` field themselves.
+  pub fn result(&self) -> std::result::Result<(i64, i64), FactomError> {
+    match FactomError::from_err_str(&self.err) {
+      None => Ok((self.ack, self.saved)),
+      Some(err) => Err(err),
+    }
+  }
+
+  /// The acknowledged balance, including in-flight transactions, as a typed
+  /// [`Factoshi`] amount.
+  pub fn ack(&self) -> Factoshi {
+    Factoshi(self.ack)
+  }
+
+  /// The last balance saved to the database, as a typed [`Factoshi`] amount.
+  pub fn saved(&self) -> Factoshi {
+    Factoshi(self.saved)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn balances(ack: i64) -> Balances {
+    Balances { ack, saved: ack, err: String::new() }
+  }
+
+  // Merging chunks takes the max of each height and concatenates the
+  // per-address balances in the order the chunks were folded in.
+  #[test]
+  fn merge_maxes_heights_and_preserves_order() {
+    let mut first = MultipleBalances {
+      currentheight: 10,
+      lastsavedheight: 8,
+      balances: vec![balances(1), balances(2)],
+    };
+    let second = MultipleBalances {
+      currentheight: 7,
+      lastsavedheight: 9,
+      balances: vec![balances(3)],
+    };
+    first.merge(second);
+    assert_eq!(first.currentheight, 10);
+    assert_eq!(first.lastsavedheight, 9);
+    assert_eq!(
+      first.balances.iter().map(|b| b.ack).collect::<Vec<_>>(),
+      vec![1, 2, 3]
+    );
+  }
+
+  #[test]
+  fn factoshi_display_formats_eight_decimals() {
+    assert_eq!(Factoshi(12_345_678).to_string(), "0.12345678");
+    assert_eq!(Factoshi(100_000_000).to_string(), "1.00000000");
+    assert_eq!(Factoshi(-150_000_000).to_string(), "-1.50000000");
+  }
+
+  #[test]
+  fn factoshi_display_handles_i64_min() {
+    // Must not panic; i64::abs would overflow here.
+    assert!(Factoshi(i64::min_value()).to_string().starts_with('-'));
+  }
+
+  #[test]
+  fn factoshi_from_str_round_trips() {
+    assert_eq!("1.5".parse::<Factoshi>().unwrap(), Factoshi(150_000_000));
+    assert_eq!("0.12345678".parse::<Factoshi>().unwrap(), Factoshi(12_345_678));
+    assert_eq!(".5".parse::<Factoshi>().unwrap(), Factoshi(50_000_000));
+    assert_eq!("2.".parse::<Factoshi>().unwrap(), Factoshi(200_000_000));
+    assert_eq!("-1.5".parse::<Factoshi>().unwrap(), Factoshi(-150_000_000));
+  }
+
+  #[test]
+  fn factoshi_from_str_rejects_empty_and_bare_dot() {
+    assert!("".parse::<Factoshi>().is_err());
+    assert!(".".parse::<Factoshi>().is_err());
+    assert!("-".parse::<Factoshi>().is_err());
+    assert!("1.234567890".parse::<Factoshi>().is_err());
+  }
+
+  #[test]
+  fn factoshi_from_str_rejects_overflow() {
+    assert!("100000000000000000000".parse::<Factoshi>().is_err());
+  }
+
+  #[test]
+  fn factoshi_factoid_conversions_are_checked() {
+    assert_eq!(Factoshi::from_factoids(3), Some(Factoshi(300_000_000)));
+    assert_eq!(Factoshi::from_factoids(i64::max_value()), None);
+    assert_eq!(Factoshi(250_000_000).as_factoids(), 2);
+  }
 }
\ No newline at end of file
@@ -0,0 +1,165 @@
+use super::*;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::num::Wrapping;
+use hyper::{Body, Request};
+use http::header::CONTENT_TYPE;
+
+impl Factom {
+  /// Starts a JSON-RPC batch against the factomd endpoint. Requests added to
+  /// the returned [`Batch`] are each tagged with a distinct id drawn from the
+  /// struct's wrapping id counter and dispatched as a single array body,
+  /// letting a caller fetch many heterogeneous queries in one round trip.
+  ///
+  /// # Example
+  /// ```
+  /// use factom::*;
+  ///
+  /// let factom = Factom::new();
+  /// let a = ApiRequest::new("factoid-balance");
+  /// let b = ApiRequest::new("entry-credit-balance");
+  /// let query = factom.batch().add(a).add(b).send();
+  /// ```
+  pub fn batch(self) -> Batch {
+    Batch {
+      id: self.id,
+      factom: self,
+      requests: Vec::new(),
+    }
+  }
+}
+
+/// Accumulates [`ApiRequest`]s to be sent to factomd as a single JSON-RPC
+/// array. Each added request is assigned a distinct id so the returned array,
+/// which factomd is free to reorder, can be de-multiplexed back onto the
+/// caller's submission order.
+pub struct Batch {
+  factom: Factom,
+  requests: Vec<ApiRequest>,
+  id: Wrapping<usize>,
+}
+
+impl Batch {
+  /// Adds a request to the batch, assigning it the next id from the wrapping
+  /// counter. Returns `self` so calls can be chained.
+  pub fn add(mut self, mut req: ApiRequest) -> Self {
+    req.id = self.id.0;
+    self.id += Wrapping(1);
+    self.requests.push(req);
+    self
+  }
+
+  /// Serializes the accumulated requests as a single JSON array, posts them to
+  /// the factomd uri and de-multiplexes the response array back into the
+  /// original submission order by matching each response's `id`.
+  ///
+  /// The outer `Result` reflects transport or deserialization failure of the
+  /// batch as a whole; per-element JSON-RPC errors are surfaced as an `Err` in
+  /// the corresponding slot without failing the remaining elements.
+  ///
+  /// The batch builds its own request against `factomd_uri` and so does not go
+  /// through the retry/failover logic of [`factomd_call`](Factom::factomd_call);
+  /// a batch is pinned to the primary endpoint for the whole round trip.
+  pub async fn send(self) -> Result<Vec<Result<ApiResponse<Value>>>> {
+    let Batch { factom, requests, .. } = self;
+    // Preserve the submitted id order so results can be realigned afterwards.
+    let order: Vec<usize> = requests.iter().map(|req| req.id).collect();
+
+    let json = serde_json::to_string(&requests)?;
+    let request = Request::builder()
+      .method("POST")
+      .header(CONTENT_TYPE, "application/json")
+      .uri(factom.factomd_uri.clone())
+      .body(Body::from(json))?;
+
+    let res = factom.client.request(request).await?;
+    let bytes = hyper::body::to_bytes(res.into_body()).await?;
+    // Deserialize into raw values so an error-only element (one with no
+    // `result`) can't fail the whole batch; each element is decoded into an
+    // ApiResponse lazily, per slot, in reorder.
+    let values: Vec<Value> = serde_json::from_slice(&bytes)?;
+
+    // Index the (possibly reordered) elements by their id so each can be handed
+    // back to the caller in the order it was submitted. Elements without a
+    // numeric id (e.g. an `{"id":null,...}` error element) are dropped here and
+    // surface as Unmatched in their slot. A whole-batch error sent as a single
+    // object fails the array deserialization above instead.
+    let by_id: HashMap<usize, Value> = values
+      .into_iter()
+      .filter_map(|v| {
+        v.get("id")
+          .and_then(Value::as_u64)
+          .map(|id| (id as usize, v))
+      })
+      .collect();
+
+    Ok(reorder(order, by_id))
+  }
+}
+
+/// Realigns response elements keyed by id back onto the submitted id order.
+/// A slot with no matching response becomes `Unmatched`; a matched element
+/// carrying a JSON-RPC `error` object becomes that `Protocol` error; otherwise
+/// the element is decoded into an [`ApiResponse`]. Per-element errors never
+/// fail the rest of the batch.
+fn reorder(order: Vec<usize>, mut by_id: HashMap<usize, Value>) -> Vec<Result<ApiResponse<Value>>> {
+  order
+    .into_iter()
+    .map(|id| match by_id.remove(&id) {
+      None => Err(FactomError::Unmatched(id)),
+      Some(value) => match FactomError::from_response(&value) {
+        Some(err) => Err(err),
+        None => serde_json::from_value(value).map_err(FactomError::from),
+      },
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // factomd is free to reorder a batch response; reorder must realign each
+  // element onto the original submission order by id.
+  #[test]
+  fn demux_preserves_submission_order() {
+    let order = vec![3, 4, 5];
+    let mut by_id = HashMap::new();
+    by_id.insert(3, json!({"jsonrpc": "2.0", "id": 3, "result": "a"}));
+    by_id.insert(5, json!({"jsonrpc": "2.0", "id": 5, "result": "c"}));
+    by_id.insert(4, json!({"jsonrpc": "2.0", "id": 4, "result": "b"}));
+    let out = reorder(order, by_id);
+    let got: Vec<Value> = out.into_iter().map(|r| r.unwrap().result).collect();
+    assert_eq!(got, vec![json!("a"), json!("b"), json!("c")]);
+  }
+
+  #[test]
+  fn demux_surfaces_unmatched_ids() {
+    let order = vec![1, 2];
+    let mut by_id = HashMap::new();
+    by_id.insert(1, json!({"jsonrpc": "2.0", "id": 1, "result": "a"}));
+    let out = reorder(order, by_id);
+    assert!(out[0].is_ok());
+    assert_eq!(out[1], Err(FactomError::Unmatched(2)));
+  }
+
+  // A single element carrying a JSON-RPC error must land in its own slot as an
+  // Err without failing the sibling that succeeded.
+  #[test]
+  fn demux_surfaces_per_element_errors() {
+    let order = vec![1, 2];
+    let mut by_id = HashMap::new();
+    by_id.insert(1, json!({"jsonrpc": "2.0", "id": 1, "error": {"code": -32602, "message": "Invalid params"}}));
+    by_id.insert(2, json!({"jsonrpc": "2.0", "id": 2, "result": "ok"}));
+    let out = reorder(order, by_id);
+    assert_eq!(
+      out[0],
+      Err(FactomError::Protocol {
+        code: -32602,
+        message: "Invalid params".to_string(),
+        data: None,
+      })
+    );
+    assert!(out[1].is_ok());
+  }
+}
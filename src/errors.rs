@@ -0,0 +1,121 @@
+use super::*;
+use serde_json::Value;
+use std::fmt;
+
+/// The crate-wide result type. Every fallible call resolves to a
+/// [`FactomError`], the single error type the client reports.
+pub type Result<T> = std::result::Result<T, FactomError>;
+
+/// A typed view of the failure modes a factomd JSON-RPC call can surface.
+///
+/// `parse`/`factomd_call` map their raw outcomes into one of these variants so
+/// callers can branch on the condition programmatically instead of matching on
+/// the transport layer or on in-band `err` strings by hand.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FactomError {
+  /// The HTTP request never produced a well-formed JSON-RPC response, e.g. a
+  /// connection error, a 5xx status or a body that failed to deserialize.
+  Transport(String),
+  /// A JSON-RPC protocol error returned in the response `error` object. Carries
+  /// the numeric `code` (e.g. `-32600 Invalid Request`, `-32602 Invalid
+  /// params`), its `message` and the optional `data` payload.
+  Protocol {
+    code: i64,
+    message: String,
+    data: Option<Value>,
+  },
+  /// factomd has not finished loading up to the last saved block
+  /// (`"Not fully booted"`).
+  NotBooted,
+  /// An address in the request could not be decoded
+  /// (`"Error decoding address"`).
+  AddressDecode,
+  /// The address is valid but has never been part of a transaction
+  /// (`"Address has not had a transaction"`).
+  NoTransaction,
+  /// Any other in-band `err` string reported against an address.
+  Node(String),
+  /// A batched request's id had no matching element in the response array.
+  Unmatched(usize),
+}
+
+impl FactomError {
+  /// Builds the appropriate variant from a JSON-RPC `error` object. The well
+  /// known codes are kept as a `Protocol` variant so the numeric code remains
+  /// inspectable by the caller.
+  pub fn from_rpc(code: i64, message: String, data: Option<Value>) -> Self {
+    FactomError::Protocol { code, message, data }
+  }
+
+  /// Classifies the per-address `err` field returned inside a `Balances`
+  /// element. An empty string means success and yields `None`.
+  pub fn from_err_str(err: &str) -> Option<Self> {
+    match err {
+      "" => None,
+      "Not fully booted" => Some(FactomError::NotBooted),
+      "Error decoding address" => Some(FactomError::AddressDecode),
+      "Address has not had a transaction" => Some(FactomError::NoTransaction),
+      other => Some(FactomError::Node(other.to_string())),
+    }
+  }
+
+  /// Inspects a decoded JSON-RPC response for a top-level `error` object and,
+  /// if present, maps its `code`/`message`/`data` onto the
+  /// [`Protocol`](FactomError::Protocol) variant so `-32600`/`-32602` and the
+  /// like surface as typed errors rather than deserialization failures.
+  pub fn from_response(value: &Value) -> Option<Self> {
+    let error = value.get("error")?;
+    let code = error.get("code").and_then(Value::as_i64).unwrap_or_default();
+    let message = error
+      .get("message")
+      .and_then(Value::as_str)
+      .unwrap_or_default()
+      .to_string();
+    let data = error.get("data").cloned();
+    Some(FactomError::from_rpc(code, message, data))
+  }
+
+  /// Whether a failed call is worth retrying against the same or the next
+  /// endpoint. Only transient conditions (transport failures and a node that
+  /// is still booting) retry; protocol and address errors are terminal.
+  pub fn is_retriable(&self) -> bool {
+    matches!(self, FactomError::Transport(_) | FactomError::NotBooted)
+  }
+}
+
+impl From<hyper::Error> for FactomError {
+  fn from(err: hyper::Error) -> Self {
+    FactomError::Transport(err.to_string())
+  }
+}
+
+impl From<http::Error> for FactomError {
+  fn from(err: http::Error) -> Self {
+    FactomError::Transport(err.to_string())
+  }
+}
+
+impl From<serde_json::Error> for FactomError {
+  fn from(err: serde_json::Error) -> Self {
+    FactomError::Transport(err.to_string())
+  }
+}
+
+impl fmt::Display for FactomError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      FactomError::Transport(msg) => write!(f, "transport error: {}", msg),
+      FactomError::Protocol { code, message, data } => match data {
+        Some(data) => write!(f, "json-rpc error {}: {} ({})", code, message, data),
+        None => write!(f, "json-rpc error {}: {}", code, message),
+      },
+      FactomError::NotBooted => write!(f, "node is not fully booted"),
+      FactomError::AddressDecode => write!(f, "error decoding address"),
+      FactomError::NoTransaction => write!(f, "address has not had a transaction"),
+      FactomError::Node(msg) => write!(f, "node error: {}", msg),
+      FactomError::Unmatched(id) => write!(f, "no response matched request id {}", id),
+    }
+  }
+}
+
+impl std::error::Error for FactomError {}
@@ -0,0 +1,133 @@
+use super::*;
+use std::time::{Duration, SystemTime};
+use hyper::{Body, Request, Response};
+use http::header::CONTENT_TYPE;
+use tokio::time::delay_for;
+
+impl Factom {
+  /// Dispatches a single JSON-RPC request to factomd, surviving transient
+  /// upstream failures. Each endpoint in `factomd_uris` is tried up to
+  /// `retry.max_attempts` times with exponential backoff; connection errors,
+  /// 5xx responses and the in-band `"Not fully booted"` status are treated as
+  /// retriable. Once an endpoint is exhausted the next one is tried, and if
+  /// every endpoint fails an aggregated error listing each failure is returned.
+  pub async fn factomd_call(&self, req: ApiRequest) -> Result<Response<Body>> {
+    let json = serde_json::to_string(&req)?;
+    let mut failures = Vec::new();
+
+    for uri in &self.factomd_uris {
+      for attempt in 0..self.retry.max_attempts {
+        match self.try_endpoint(uri, &json).await {
+          Ok(response) => return Ok(response),
+          // A protocol or address error is the node's considered answer, not a
+          // transient fault, so surface it immediately instead of retrying.
+          Err(err) if !err.is_retriable() => return Err(err),
+          Err(err) => {
+            failures.push(format!("{}: {}", uri, err));
+            // Don't sleep after the final attempt for this endpoint.
+            if attempt + 1 < self.retry.max_attempts {
+              delay_for(self.retry.jittered(self.retry.base_backoff(attempt))).await;
+            }
+          }
+        }
+      }
+    }
+    Err(FactomError::Transport(format!(
+      "all factomd endpoints failed: [{}]",
+      failures.join("; ")
+    )))
+  }
+
+  /// Performs one HTTP attempt against a single endpoint, buffering the body so
+  /// the in-band `"Not fully booted"` status can be detected and treated as a
+  /// retriable failure before the response is handed back to `parse`.
+  async fn try_endpoint(&self, uri: &Uri, json: &str) -> std::result::Result<Response<Body>, FactomError> {
+    let request = Request::builder()
+      .method("POST")
+      .header(CONTENT_TYPE, "application/json")
+      .uri(uri.clone())
+      .body(Body::from(json.to_owned()))
+      .map_err(|e| FactomError::Transport(e.to_string()))?;
+
+    let response = self
+      .client
+      .request(request)
+      .await
+      .map_err(|e| FactomError::Transport(e.to_string()))?;
+
+    let status = response.status();
+    if status.is_server_error() {
+      return Err(FactomError::Transport(format!("server error {}", status)));
+    }
+
+    let bytes = hyper::body::to_bytes(response.into_body())
+      .await
+      .map_err(|e| FactomError::Transport(e.to_string()))?;
+    if bytes.windows(NOT_BOOTED.len()).any(|w| w == NOT_BOOTED) {
+      return Err(FactomError::NotBooted);
+    }
+    // Map a top-level JSON-RPC `error` object (e.g. -32600/-32602) onto a typed
+    // error before the body is handed back to `parse`.
+    if let Ok(value) = serde_json::from_slice::<serde_json::Value>(&bytes) {
+      if let Some(err) = FactomError::from_response(&value) {
+        return Err(err);
+      }
+    }
+    Ok(Response::new(Body::from(bytes)))
+  }
+}
+
+/// The in-band status factomd returns while it is still syncing.
+const NOT_BOOTED: &[u8] = b"Not fully booted";
+
+impl RetryPolicy {
+  /// The un-jittered backoff for a zero-based `attempt`: `base_delay` scaled by
+  /// `multiplier` raised to the attempt number, so the first retry waits
+  /// `base_delay` and each subsequent one grows geometrically.
+  fn base_backoff(&self, attempt: u32) -> Duration {
+    self.base_delay.mul_f64(self.multiplier.powi(attempt as i32))
+  }
+
+  /// Adds up to `jitter` (as a fraction of `delay`) of randomness to a backoff
+  /// interval so simultaneous retries spread out rather than stampede a node.
+  fn jittered(&self, delay: Duration) -> Duration {
+    if self.jitter <= 0.0 {
+      return delay;
+    }
+    // A cheap randomness source; the client doesn't depend on `rand`.
+    let nanos = SystemTime::now()
+      .duration_since(SystemTime::UNIX_EPOCH)
+      .map(|d| d.subsec_nanos())
+      .unwrap_or(0);
+    let fraction = (nanos % 1_000) as f64 / 1_000.0;
+    delay + delay.mul_f64(self.jitter * fraction)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // The backoff must grow geometrically from base_delay by the multiplier.
+  #[test]
+  fn backoff_grows_geometrically() {
+    let policy = RetryPolicy {
+      max_attempts: 4,
+      base_delay: Duration::from_millis(100),
+      multiplier: 2.0,
+      jitter: 0.0,
+    };
+    assert_eq!(policy.base_backoff(0), Duration::from_millis(100));
+    assert_eq!(policy.base_backoff(1), Duration::from_millis(200));
+    assert_eq!(policy.base_backoff(2), Duration::from_millis(400));
+    assert_eq!(policy.base_backoff(3), Duration::from_millis(800));
+  }
+
+  // With zero jitter the delay is returned unchanged.
+  #[test]
+  fn zero_jitter_is_identity() {
+    let policy = RetryPolicy { jitter: 0.0, ..RetryPolicy::default() };
+    let delay = Duration::from_millis(250);
+    assert_eq!(policy.jittered(delay), delay);
+  }
+}
@@ -0,0 +1,117 @@
+use super::*;
+use std::time::Duration;
+use futures::stream::{self, Stream};
+
+impl Factom {
+  /// Returns a stream that polls the factoid balance of `address` every
+  /// `interval` and yields only when the saved `balance` changes from the
+  /// previously observed value, deduplicating unchanged polls.
+  ///
+  /// # Example
+  /// ```
+  /// use factom::*;
+  /// use std::time::Duration;
+  ///
+  /// let factom = Factom::new();
+  /// let stream = factom.watch_factoid_balance("FA2jK2HcLnRdS94dEcU27rF3meoJfpUcZPSinpb7AwQvPRY6RL1Q",
+  ///                                           Duration::from_secs(10));
+  /// ```
+  pub fn watch_factoid_balance(self, address: &str, interval: Duration)
+    -> impl Stream<Item = Result<Balance>>
+  {
+    self.watch_balance(Watch::FactoidBalance, address, interval)
+  }
+
+  /// Returns a stream that polls the entry credit balance of `address` every
+  /// `interval`, yielding only on a change to the saved `balance`.
+  pub fn watch_ec_balance(self, address: &str, interval: Duration)
+    -> impl Stream<Item = Result<Balance>>
+  {
+    self.watch_balance(Watch::EcBalance, address, interval)
+  }
+
+  /// Like [`watch_factoid_balance`](Factom::watch_factoid_balance) but keys
+  /// change detection on the acknowledged (`ack`) value reported by
+  /// `multiple_fct_balances`, so a caller observes pending transactions before
+  /// they are saved to the database.
+  pub fn watch_factoid_balance_ack(self, address: &str, interval: Duration)
+    -> impl Stream<Item = Result<Balance>>
+  {
+    self.watch_balance(Watch::FactoidAck, address, interval)
+  }
+
+  /// The entry credit counterpart of
+  /// [`watch_factoid_balance_ack`](Factom::watch_factoid_balance_ack).
+  pub fn watch_ec_balance_ack(self, address: &str, interval: Duration)
+    -> impl Stream<Item = Result<Balance>>
+  {
+    self.watch_balance(Watch::EcAck, address, interval)
+  }
+
+  /// Drives the polling loop for every `watch_*` method: a timer ticks each
+  /// `interval`, the relevant balance call runs against a clone of this struct,
+  /// and the value is emitted only when it differs from the last one seen.
+  /// Errors are forwarded as-is without advancing the remembered value.
+  fn watch_balance(self, watch: Watch, address: &str, interval: Duration)
+    -> impl Stream<Item = Result<Balance>>
+  {
+    let address = address.to_string();
+    let ticker = tokio::time::interval(interval);
+    let state = WatchState { factom: self, watch, address, ticker, last: None };
+    stream::unfold(state, |mut state| async move {
+      loop {
+        state.ticker.tick().await;
+        match state.watch.poll(state.factom.clone(), &state.address).await {
+          Ok(value) => {
+            if state.last == Some(value) {
+              continue;
+            }
+            state.last = Some(value);
+            return Some((Ok(Balance::from_factoshis(value)), state));
+          }
+          Err(err) => return Some((Err(err), state)),
+        }
+      }
+    })
+  }
+}
+
+/// Which balance call a watch stream issues and which field it tracks.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Watch {
+  FactoidBalance,
+  EcBalance,
+  FactoidAck,
+  EcAck,
+}
+
+impl Watch {
+  /// Issues the underlying balance call and extracts the tracked value.
+  async fn poll(self, factom: Factom, address: &str) -> Result<i64> {
+    match self {
+      Watch::FactoidBalance => {
+        Ok(factom.factoid_balance(address).await?.result.balance().factoshis())
+      }
+      Watch::EcBalance => {
+        Ok(factom.entry_credit_balance(address).await?.result.balance().factoshis())
+      }
+      Watch::FactoidAck => {
+        let response = factom.multiple_fct_balances(vec![address]).await?;
+        Ok(response.result.balances().first().map(|b| b.ack().factoshis()).unwrap_or_default())
+      }
+      Watch::EcAck => {
+        let response = factom.multiple_ec_balances(vec![address]).await?;
+        Ok(response.result.balances().first().map(|b| b.ack().factoshis()).unwrap_or_default())
+      }
+    }
+  }
+}
+
+/// Mutable state threaded through the polling stream.
+struct WatchState {
+  factom: Factom,
+  watch: Watch,
+  address: String,
+  ticker: tokio::time::Interval,
+  last: Option<i64>,
+}